@@ -9,20 +9,409 @@ use depict::{graph_drawing::{
 
 use dioxus::{prelude::*};
 
+use base64::Engine;
 use futures::StreamExt;
 use indoc::indoc;
 
 use tracing::{event, Level};
 
+// ============================================================================
+// SYNTAX HIGHLIGHTING - tokenizes the depict DSL so the editor can draw a
+// colored overlay (and, later, error squiggles) over the plain textarea.
+// ============================================================================
+mod highlight {
+    use std::ops::Range;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum TokenKind {
+        Noun,
+        EdgeLabel,
+        RelationSeparator,
+        ForwardReverseSeparator,
+        Comment,
+    }
+
+    /// Tokenizes a depict model (e.g. `person microwave food: open, start,
+    /// stop / beep : heat`) into `(TokenKind, byte range)` pairs, in order,
+    /// for highlighting. Shared by the editor overlay and, eventually, error
+    /// squiggles, so both stay in sync with the same notion of a token.
+    pub fn highlight_model(model: &str) -> Vec<(TokenKind, Range<usize>)> {
+        let mut tokens = Vec::new();
+
+        for line in model.split_inclusive('\n') {
+            let line_start = line.as_ptr() as usize - model.as_ptr() as usize;
+            let trimmed = line.trim_end_matches('\n');
+
+            if let Some(comment_start) = trimmed.find('#') {
+                highlight_segment(&trimmed[..comment_start], line_start, &mut tokens);
+                tokens.push((
+                    TokenKind::Comment,
+                    line_start + comment_start..line_start + trimmed.len(),
+                ));
+            } else {
+                highlight_segment(trimmed, line_start, &mut tokens);
+            }
+        }
+
+        tokens
+    }
+
+    // A line minus its trailing comment: nouns before the first `:`, then
+    // alternating relation text (edge labels plus `/` separators) and `:`
+    // relation separators.
+    fn highlight_segment(segment: &str, offset: usize, tokens: &mut Vec<(TokenKind, Range<usize>)>) {
+        let mut rest = segment;
+        let mut rest_offset = offset;
+        let mut seen_colon = false;
+
+        loop {
+            match rest.find(':') {
+                Some(colon_idx) => {
+                    let (before, after) = rest.split_at(colon_idx);
+                    if seen_colon {
+                        highlight_words(before, rest_offset, TokenKind::EdgeLabel, tokens);
+                    } else {
+                        highlight_words(before, rest_offset, TokenKind::Noun, tokens);
+                    }
+                    tokens.push((TokenKind::RelationSeparator, rest_offset + colon_idx..rest_offset + colon_idx + 1));
+                    seen_colon = true;
+                    rest_offset += colon_idx + 1;
+                    rest = &after[1..];
+                }
+                None => {
+                    if seen_colon {
+                        highlight_words(rest, rest_offset, TokenKind::EdgeLabel, tokens);
+                    } else {
+                        highlight_words(rest, rest_offset, TokenKind::Noun, tokens);
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    // Splits a comma/slash-delimited word list into individual tokens,
+    // surfacing `/` as its own `ForwardReverseSeparator` token.
+    fn highlight_words(text: &str, offset: usize, kind: TokenKind, tokens: &mut Vec<(TokenKind, Range<usize>)>) {
+        let mut word_start: Option<usize> = None;
+
+        for (idx, ch) in text.char_indices() {
+            let is_boundary = ch == ',' || ch == '/' || ch.is_whitespace();
+
+            if is_boundary {
+                if let Some(start) = word_start.take() {
+                    tokens.push((kind, offset + start..offset + idx));
+                }
+                if ch == '/' {
+                    tokens.push((TokenKind::ForwardReverseSeparator, offset + idx..offset + idx + 1));
+                }
+            } else if word_start.is_none() {
+                word_start = Some(idx);
+            }
+        }
+
+        if let Some(start) = word_start {
+            tokens.push((kind, offset + start..offset + text.len()));
+        }
+    }
+
+    /// Splits `model` into `(token kind, text)` segments covering every
+    /// byte, gluing in the untokenized punctuation/whitespace as plain
+    /// (`None`) segments so the overlay can reconstruct the original text
+    /// exactly and stay pixel-aligned with the textarea underneath.
+    pub fn segments(model: &str) -> Vec<(Option<TokenKind>, String)> {
+        let mut out = Vec::new();
+        let mut cursor = 0;
+
+        for (kind, range) in highlight_model(model) {
+            if range.start > cursor {
+                out.push((None, model[cursor..range.start].to_string()));
+            }
+            out.push((Some(kind), model[range.clone()].to_string()));
+            cursor = range.end;
+        }
+
+        if cursor < model.len() {
+            out.push((None, model[cursor..].to_string()));
+        }
+
+        out
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn highlights_nouns_and_relation_separator() {
+            let tokens = highlight_model("person microwave: heat");
+            assert_eq!(
+                tokens,
+                vec![
+                    (TokenKind::Noun, 0..6),
+                    (TokenKind::Noun, 7..16),
+                    (TokenKind::RelationSeparator, 16..17),
+                    (TokenKind::EdgeLabel, 18..22),
+                ]
+            );
+        }
+
+        #[test]
+        fn highlights_forward_reverse_separator_and_comment() {
+            let tokens = highlight_model("a: open / close # note");
+            assert!(tokens.contains(&(TokenKind::ForwardReverseSeparator, 8..9)));
+            assert!(tokens.iter().any(|(kind, _)| *kind == TokenKind::Comment));
+        }
+
+        #[test]
+        fn segments_reconstruct_the_original_text() {
+            let model = "person microwave: heat # warm it up";
+            let rebuilt: String = segments(model).into_iter().map(|(_, text)| text).collect();
+            assert_eq!(rebuilt, model);
+        }
+
+        #[test]
+        fn segments_on_empty_model_is_empty() {
+            assert_eq!(segments(""), Vec::new());
+        }
+    }
+}
+
+// ============================================================================
+// VIEWPORT - pan/zoom framing for the drawing pane: a `{ translate, scale }`
+// pair applied as a CSS transform to the `nodes` container, with `zoom_at`/
+// `pan_by`/`fit` as the pure math behind wheel-zoom, drag-pan, and the "Fit
+// to view" button respectively.
+// ============================================================================
+mod viewport {
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct Viewport {
+        pub translate: (f32, f32),
+        pub scale: f32,
+    }
+
+    impl Default for Viewport {
+        fn default() -> Self {
+            Viewport { translate: (0., 0.), scale: 1. }
+        }
+    }
+
+    impl Viewport {
+        pub const MIN_SCALE: f32 = 0.1;
+        pub const MAX_SCALE: f32 = 8.0;
+
+        /// CSS `transform` for the nodes container.
+        pub fn css_transform(&self) -> String {
+            format!(
+                "transform: translate({}px, {}px) scale({}); transform-origin: 0 0;",
+                self.translate.0, self.translate.1, self.scale
+            )
+        }
+
+        /// Zoom by `factor`, keeping the point under the cursor
+        /// (`cursor_x`/`cursor_y`, in container-local pixels) fixed on screen.
+        pub fn zoom_at(&self, cursor_x: f32, cursor_y: f32, factor: f32) -> Viewport {
+            let new_scale = (self.scale * factor).clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+            let actual_factor = new_scale / self.scale;
+            let translate = (
+                cursor_x - actual_factor * (cursor_x - self.translate.0),
+                cursor_y - actual_factor * (cursor_y - self.translate.1),
+            );
+            Viewport { translate, scale: new_scale }
+        }
+
+        /// Pan by a pixel delta.
+        pub fn pan_by(&self, dx: f32, dy: f32) -> Viewport {
+            Viewport { translate: (self.translate.0 + dx, self.translate.1 + dy), scale: self.scale }
+        }
+
+        /// Scale so the drawing (`content_width` x `content_height`) fits
+        /// entirely within `container_width` x `container_height`, centered.
+        pub fn fit(content_width: f32, content_height: f32, container_width: f32, container_height: f32) -> Viewport {
+            if content_width <= 0. || content_height <= 0. {
+                return Viewport::default();
+            }
+            let scale = (container_width / content_width)
+                .min(container_height / content_height)
+                .clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+            let translate = (
+                (container_width - content_width * scale) / 2.,
+                (container_height - content_height * scale) / 2.,
+            );
+            Viewport { translate, scale }
+        }
+    }
+
+    /// Wraps `svg`'s contents in a `<g transform="...">` carrying `view`'s
+    /// pan/zoom, so an exported SVG can optionally reflect the framing
+    /// currently on screen instead of always the untransformed drawing.
+    /// Pure string surgery: assumes `svg` is a single `<svg ...>...</svg>`
+    /// document with no literal `>` inside the opening tag's attributes.
+    pub fn wrap_svg_with_transform(svg: &str, view: &Viewport) -> String {
+        let Some(tag_end) = svg.find('>') else { return svg.to_string() };
+        let (open, rest) = svg.split_at(tag_end + 1);
+        let Some(inner) = rest.strip_suffix("</svg>") else { return svg.to_string() };
+
+        format!(
+            "{open}<g transform=\"translate({tx}, {ty}) scale({scale})\">{inner}</g></svg>",
+            tx = view.translate.0,
+            ty = view.translate.1,
+            scale = view.scale,
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn zoom_at_keeps_cursor_point_fixed() {
+            let view = Viewport::default();
+            let zoomed = view.zoom_at(100., 100., 2.0);
+            assert_eq!(zoomed.scale, 2.0);
+            // The cursor point itself shouldn't move on screen.
+            assert_eq!(zoomed.translate, (-100., -100.));
+        }
+
+        #[test]
+        fn zoom_at_clamps_to_scale_bounds() {
+            let view = Viewport::default();
+            let zoomed_in = view.zoom_at(0., 0., 1000.0);
+            assert_eq!(zoomed_in.scale, Viewport::MAX_SCALE);
+            let zoomed_out = view.zoom_at(0., 0., 0.0001);
+            assert_eq!(zoomed_out.scale, Viewport::MIN_SCALE);
+        }
+
+        #[test]
+        fn pan_by_only_moves_translate() {
+            let view = Viewport { translate: (1., 2.), scale: 3. };
+            let panned = view.pan_by(10., -5.);
+            assert_eq!(panned.translate, (11., -3.));
+            assert_eq!(panned.scale, 3.);
+        }
+
+        #[test]
+        fn fit_centers_and_scales_down_to_container() {
+            let view = Viewport::fit(200., 100., 100., 100.);
+            assert_eq!(view.scale, 0.5);
+            assert_eq!(view.translate, (0., 25.));
+        }
+
+        #[test]
+        fn fit_on_degenerate_content_is_default() {
+            assert!(Viewport::fit(0., 100., 100., 100.) == Viewport::default());
+        }
+
+        #[test]
+        fn wrap_svg_with_transform_inserts_a_single_group() {
+            let svg = r#"<svg width="10" height="10"><rect/></svg>"#;
+            let view = Viewport { translate: (5., 6.), scale: 2. };
+            let wrapped = wrap_svg_with_transform(svg, &view);
+            assert_eq!(
+                wrapped,
+                r#"<svg width="10" height="10"><g transform="translate(5, 6) scale(2)"><rect/></g></svg>"#
+            );
+        }
+    }
+}
+
+// ============================================================================
+// PERMALINKS - makes the URL a bidirectional state channel: `?m=` carries a
+// deflate+base64url-encoded model so real models (which blow past plain
+// percent-encoded URL length limits) still fit in a shareable address-bar
+// link. `?input=` remains readable for backward compatibility.
+// ============================================================================
+mod permalink {
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+    use std::io::{Read, Write};
+
+    /// Deflates `model`'s UTF-8 bytes and base64url-encodes the result
+    /// (no padding, so it's safe unescaped in a query string).
+    pub fn encode_model(model: &str) -> String {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        // `Vec<u8>`'s `Write` impl never fails.
+        encoder.write_all(model.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        URL_SAFE_NO_PAD.encode(compressed)
+    }
+
+    /// Inverse of [`encode_model`]. Returns `None` if `encoded` isn't valid
+    /// base64url, doesn't inflate, or doesn't decode as UTF-8.
+    pub fn decode_model(encoded: &str) -> Option<String> {
+        let compressed = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+        let mut decoder = DeflateDecoder::new(&compressed[..]);
+        let mut model = String::new();
+        decoder.read_to_string(&mut model).ok()?;
+        Some(model)
+    }
+
+    /// Replaces the address bar's whole query string with a single `?m=`
+    /// parameter holding `model`'s compressed encoding, via
+    /// `history.replaceState`, so the current page is always a
+    /// shareable permalink without adding a new history entry per keystroke.
+    #[cfg(target_arch = "wasm32")]
+    pub fn sync_url(model: &str) {
+        let Some(window) = web_sys::window() else { return };
+        let Ok(history) = window.history() else { return };
+        let location = window.location();
+        let Ok(pathname) = location.pathname() else { return };
+
+        let new_url = format!("{pathname}?m={}", encode_model(model));
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&new_url));
+    }
+
+    /// Builds a shareable link for "Copy link": the current page location
+    /// with `?m=` set to `model`'s compressed encoding.
+    pub fn share_url(model: &str) -> String {
+        let origin = web_sys::window()
+            .and_then(|w| w.location().origin().ok())
+            .unwrap_or_default();
+        let pathname = web_sys::window()
+            .and_then(|w| w.location().pathname().ok())
+            .unwrap_or_default();
+        format!("{origin}{pathname}?m={}", encode_model(model))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn encode_decode_round_trips() {
+            let model = "person microwave food: open, start, stop / beep : heat";
+            let encoded = encode_model(model);
+            assert_eq!(decode_model(&encoded).as_deref(), Some(model));
+        }
+
+        #[test]
+        fn encode_decode_round_trips_on_empty_model() {
+            let encoded = encode_model("");
+            assert_eq!(decode_model(&encoded).as_deref(), Some(""));
+        }
+
+        #[test]
+        fn decode_rejects_garbage() {
+            assert_eq!(decode_model("not valid base64url!!"), None);
+        }
+    }
+}
+
 // ============================================================================
 // FEATURE FLAGS - Change these to enable/disable features at compile time
 // Set all to false to get back to the original code behavior
 // ============================================================================
-const ENABLE_STATUS_TRACKING: bool = false;
-const ENABLE_TIMEOUT_DETECTION: bool = false;
-const ENABLE_HISTORY: bool = false;
+const ENABLE_STATUS_TRACKING: bool = true;
+const ENABLE_TIMEOUT_DETECTION: bool = true;
+const ENABLE_HISTORY: bool = true;
+
+// Debounce window for coalescing per-keystroke edits into one history entry.
+const HISTORY_SETTLE_MS: u32 = 500;
 const ENABLE_TEST_CONTROLS: bool = false;
 
+// How long to wait for a worker response before flipping to `AppStatus::Timeout`.
+const DRAW_TIMEOUT_MS: u32 = 5_000;
+
 // Only import gloo_timers if timeout detection is enabled
 #[cfg(all(target_arch = "wasm32"))]
 mod timeout_support {
@@ -30,6 +419,187 @@ mod timeout_support {
     pub use gloo_timers::future::TimeoutFuture;
 }
 
+// ============================================================================
+// LAYOUT WORKER - runs `draw()` off the main thread so a pathological model
+// can't freeze the editor. `DrawRequest`/`DrawResponse` are the message types
+// exchanged with the dedicated Web Worker spawned by `worker_bridge` below;
+// `run_worker` is the entry point `worker.js` calls after loading this same
+// wasm module into that worker's global scope, and `handle_request` is the
+// pure computation it (and the non-wasm fallback, which just calls it
+// inline) runs per request.
+// ============================================================================
+mod worker {
+    use depict::graph_drawing::frontend::dom::Drawing;
+    use serde::{Deserialize, Serialize};
+
+    /// Sent from the main thread to the worker for every keystroke.
+    #[derive(Clone)]
+    pub struct DrawRequest {
+        pub req_id: u64,
+        pub model: String,
+    }
+
+    /// Sent back from the worker once layout finishes (or panics).
+    #[derive(Clone)]
+    pub struct DrawResponse {
+        pub req_id: u64,
+        pub result: Result<Drawing, String>,
+    }
+
+    // `DrawRequest` is plain text (it's just a req_id and the model source),
+    // but `DrawResponse` carries a full `Drawing`, so it goes over the wire
+    // as JSON rather than the SEP-delimited scheme `DrawRequest` uses -
+    // `Drawing` isn't flat enough to hand-encode.
+    const SEP: char = '\u{1}';
+
+    impl DrawRequest {
+        pub fn encode(&self) -> String {
+            format!("{}{}{}", self.req_id, SEP, self.model)
+        }
+
+        pub fn decode(msg: &str) -> Option<DrawRequest> {
+            let (req_id, model) = msg.split_once(SEP)?;
+            Some(DrawRequest {
+                req_id: req_id.parse().ok()?,
+                model: model.to_string(),
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct WireResponse {
+        req_id: u64,
+        result: Result<Drawing, String>,
+    }
+
+    impl DrawResponse {
+        pub fn encode(&self) -> String {
+            let wire = WireResponse { req_id: self.req_id, result: self.result.clone() };
+            serde_json::to_string(&wire).unwrap_or_default()
+        }
+
+        pub fn decode(msg: &str) -> Option<DrawResponse> {
+            let wire: WireResponse = serde_json::from_str(msg).ok()?;
+            Some(DrawResponse { req_id: wire.req_id, result: wire.result })
+        }
+    }
+
+    /// The worker-side computation: for every request, run `draw()` behind
+    /// `catch_unwind` so a panic in layout becomes an `Err` response
+    /// instead of taking down the worker.
+    pub fn handle_request(req: DrawRequest) -> DrawResponse {
+        use std::panic::catch_unwind;
+        use depict::graph_drawing::frontend::dom::draw;
+
+        let result = if req.model.trim().is_empty() {
+            Ok(Drawing::default())
+        } else {
+            match catch_unwind(|| draw(req.model.clone())) {
+                Ok(Ok(drawing)) => Ok(drawing),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err("layout panicked".to_string()),
+            }
+        };
+
+        DrawResponse { req_id: req.req_id, result }
+    }
+
+    /// Entry point for the dedicated Web Worker (`worker.js`): listens for
+    /// `DrawRequest`s over `postMessage`, runs layout on the worker thread,
+    /// and posts `DrawResponse`s back - the actual off-main-thread half of
+    /// this protocol.
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen::prelude::wasm_bindgen]
+    pub fn run_worker() {
+        use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+        use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+        let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+        let post_to = scope.clone();
+
+        let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |evt: MessageEvent| {
+            let Some(text) = evt.data().as_string() else { return };
+            let Some(request) = DrawRequest::decode(&text) else { return };
+            let response = handle_request(request);
+            let _ = post_to.post_message(&JsValue::from_str(&response.encode()));
+        });
+
+        scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        // This worker's `onmessage` must stay live for the worker's entire
+        // lifetime, so leak the closure instead of dropping it.
+        onmessage.forget();
+    }
+}
+
+// ============================================================================
+// WORKER BRIDGE - main-thread half of the worker protocol: owns the
+// `web_sys::Worker`, matches each `DrawResponse` back to the `DrawRequest`
+// that caused it (by `req_id`), and hands the result to whoever asked via a
+// one-shot future, so the coroutine below can genuinely `.await` a reply
+// instead of computing it inline.
+// ============================================================================
+#[cfg(target_arch = "wasm32")]
+mod worker_bridge {
+    use super::worker::{DrawRequest, DrawResponse};
+    use futures::channel::oneshot;
+    use std::{cell::RefCell, collections::HashMap, rc::Rc};
+    use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+    use web_sys::{MessageEvent, Worker};
+
+    type Pending = Rc<RefCell<HashMap<u64, oneshot::Sender<DrawResponse>>>>;
+
+    pub struct WorkerBridge {
+        worker: Worker,
+        pending: Pending,
+        // Kept alive for as long as the bridge is; dropping it would detach
+        // `worker`'s `onmessage` handler.
+        _onmessage: Closure<dyn FnMut(MessageEvent)>,
+    }
+
+    impl WorkerBridge {
+        pub fn new() -> Self {
+            let worker = Worker::new("./worker.js").expect("failed to spawn layout worker");
+            let pending: Pending = Rc::new(RefCell::new(HashMap::new()));
+
+            let pending_for_closure = pending.clone();
+            let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |evt: MessageEvent| {
+                let Some(text) = evt.data().as_string() else { return };
+                let Some(response) = DrawResponse::decode(&text) else { return };
+                if let Some(sender) = pending_for_closure.borrow_mut().remove(&response.req_id) {
+                    let _ = sender.send(response);
+                }
+            });
+            worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+
+            WorkerBridge { worker, pending, _onmessage: onmessage }
+        }
+
+        /// Posts `request` to the worker. The returned receiver resolves
+        /// with its `DrawResponse` once the worker actually replies -
+        /// there's no synchronous fallback, so racing it against a
+        /// `TimeoutFuture` is a genuine race.
+        pub fn request(&self, request: DrawRequest) -> oneshot::Receiver<DrawResponse> {
+            let (sender, receiver) = oneshot::channel();
+            self.pending.borrow_mut().insert(request.req_id, sender);
+            let _ = self.worker.post_message(&JsValue::from_str(&request.encode()));
+            receiver
+        }
+    }
+
+    // A worker that's stuck computing a pathological `draw()` never yields
+    // to its `onmessage` queue, so anything we post afterward would just
+    // pile up behind it forever. Terminating it here (rather than waiting
+    // for it to finish, or never finish) also drops every sender still in
+    // `pending`, which turns any in-flight `request()` awaiting one into an
+    // `Err` - exactly the "bridge dropped the sender" case the coroutine
+    // already treats as no response.
+    impl Drop for WorkerBridge {
+        fn drop(&mut self) {
+            self.worker.terminate();
+        }
+    }
+}
+
 // ============================================================================
 // C SHIM FUNCTIONS (unchanged)
 // ============================================================================
@@ -166,67 +736,139 @@ const PLACEHOLDER: &str = indoc!("
     person food: stir
 ");
 
+// Colors for the `highlight` overlay's token classes.
+const HIGHLIGHT_CSS: &str = "
+    .tok-noun { color: #1d4ed8; font-weight: 600; }
+    .tok-edge-label { color: #047857; }
+    .tok-relation-separator { color: #000; font-weight: 700; }
+    .tok-forward-reverse-separator { color: #b45309; font-weight: 700; }
+    .tok-comment { color: #9ca3af; font-style: italic; }
+    .tok-plain { color: inherit; }
+";
+
 // ============================================================================
 // URL PARAMETER HELPER FUNCTION
 // ============================================================================
 
-/// Simple URL decoder that handles the most common URL-encoded characters
+/// Percent-decodes `encoded` into raw bytes first, then reassembles UTF-8
+/// from those bytes (rather than decoding byte-by-byte into `char`s), so
+/// multi-byte sequences like `%E2%9C%93` round-trip correctly. `+` is
+/// treated as a space, per `application/x-www-form-urlencoded`.
 fn decode_url(encoded: &str) -> String {
-    let mut decoded = String::with_capacity(encoded.len());
-    let mut chars = encoded.chars();
-    
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    let mut chars = encoded.chars().peekable();
+
     while let Some(c) = chars.next() {
         if c == '%' {
-            // Try to decode %XX where XX are hex digits
             let hex: String = chars.by_ref().take(2).collect();
             if hex.len() == 2 {
                 if let Ok(byte) = u8::from_str_radix(&hex, 16) {
-                    decoded.push(byte as char);
+                    bytes.push(byte);
                     continue;
                 }
             }
-            // If decoding fails, just keep the % and hex chars
-            decoded.push('%');
-            decoded.push_str(&hex);
+            // If decoding fails, keep the literal bytes.
+            bytes.push(b'%');
+            bytes.extend(hex.bytes());
         } else if c == '+' {
-            // '+' is often used for space in URL encoding
-            decoded.push(' ');
+            bytes.push(b' ');
         } else {
-            decoded.push(c);
+            let mut buf = [0u8; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
         }
     }
-    
-    decoded
+
+    String::from_utf8(bytes).unwrap_or_default()
 }
 
-/// Extracts the 'input' parameter from the URL query string and decodes it.
-/// Returns the decoded string if found, otherwise returns None.
+/// Parses `query` (without the leading `?`) into `(key, value)` pairs.
+fn url_query_pairs(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    query.split('&').filter_map(|pair| {
+        let mut parts = pair.splitn(2, '=');
+        Some((parts.next()?, parts.next()?))
+    })
+}
+
+/// Reads the current model from the URL: prefers the compressed `?m=`
+/// permalink parameter, falling back to the plaintext `?input=` parameter
+/// for backward compatibility with links shared before the `m` codec
+/// existed. Returns `None` if neither parameter is present or decodable.
 fn get_url_input_parameter() -> Option<String> {
     let window = web_sys::window()?;
     let location = window.location();
     let search = location.search().ok()?;
-    
+
     if search.is_empty() || search == "?" {
         return None;
     }
-    
-    // Parse query string manually (simple implementation)
-    // Format: ?input=encoded_value or ?other=value&input=encoded_value
+
     let query = search.trim_start_matches('?');
-    
-    for pair in query.split('&') {
-        let mut parts = pair.splitn(2, '=');
-        if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
-            if key == "input" {
-                // URL decode the value
-                return Some(decode_url(value));
+    let mut input_fallback = None;
+
+    for (key, value) in url_query_pairs(query) {
+        match key {
+            "m" => {
+                if let Some(model) = permalink::decode_model(value) {
+                    return Some(model);
+                }
             }
+            "input" => input_fallback = Some(decode_url(value)),
+            _ => {}
         }
     }
-    
-    None
+
+    input_fallback
 }
 
+/// Re-frames `data_svg` (a `data:image/svg+xml...` URL produced by
+/// `as_data_svg`) so it reflects the current on-screen pan/zoom, letting
+/// "Export SVG" export what the user is actually looking at rather than
+/// always the untransformed drawing. Applied whenever `view` differs from
+/// the default viewport; there is no setting to export the untransformed
+/// drawing while panned or zoomed. "Copy link" does not use it. A no-op
+/// (and a cheap one) when `view` is the default viewport.
+fn export_svg_with_viewport(data_svg: &str, view: &viewport::Viewport) -> String {
+    if *view == viewport::Viewport::default() {
+        return data_svg.to_string();
+    }
+
+    let Some((header, payload)) = data_svg.split_once(',') else {
+        return data_svg.to_string();
+    };
+
+    let svg = if header.ends_with(";base64") {
+        let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(payload) else {
+            return data_svg.to_string();
+        };
+        let Ok(svg) = String::from_utf8(bytes) else {
+            return data_svg.to_string();
+        };
+        svg
+    } else {
+        decode_url(payload)
+    };
+
+    let wrapped = viewport::wrap_svg_with_transform(&svg, view);
+    let encoded = base64::engine::general_purpose::STANDARD.encode(wrapped);
+    format!("data:image/svg+xml;base64,{encoded}")
+}
+
+/// Copies the model editor textarea's scroll offset onto the highlighting
+/// overlay div, so the overlay (which has `overflow: hidden` and never
+/// scrolls on its own) stays pixel-aligned with the text once the model
+/// grows past the textarea's visible `rows`.
+#[cfg(target_arch = "wasm32")]
+fn sync_overlay_scroll() {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+    let Some(editor) = document.get_element_by_id("model_editor") else { return };
+    let Some(overlay) = document.get_element_by_id("model_highlight_overlay") else { return };
+    overlay.set_scroll_top(editor.scroll_top());
+    overlay.set_scroll_left(editor.scroll_left());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sync_overlay_scroll() {}
+
 // ============================================================================
 // FEATURE-SPECIFIC DATA STRUCTURES - only defined if needed
 // ============================================================================
@@ -262,6 +904,115 @@ pub struct HistoryEntry {
     pub drawing: Drawing,
 }
 
+// Bounded undo/redo stack over `HistoryEntry`. `cursor` points at the entry
+// currently shown; undo/redo move it without touching `entries`, so editing
+// after an undo (a `push`) truncates everything past `cursor` - the usual
+// "branching discards the redo tail" editor semantics.
+pub struct History {
+    entries: Vec<HistoryEntry>,
+    cursor: usize,
+}
+
+impl History {
+    const MAX_ENTRIES: usize = 100;
+
+    fn new() -> Self {
+        History { entries: Vec::new(), cursor: 0 }
+    }
+
+    fn push(&mut self, entry: HistoryEntry) {
+        self.entries.truncate(self.cursor.saturating_add(if self.entries.is_empty() { 0 } else { 1 }));
+        self.entries.push(entry);
+        if self.entries.len() > Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.cursor = self.entries.len() - 1;
+    }
+
+    fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+
+    fn undo(&mut self) -> Option<&HistoryEntry> {
+        if self.can_undo() {
+            self.cursor -= 1;
+            self.entries.get(self.cursor)
+        } else {
+            None
+        }
+    }
+
+    fn redo(&mut self) -> Option<&HistoryEntry> {
+        if self.can_redo() {
+            self.cursor += 1;
+            self.entries.get(self.cursor)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod history_tests {
+    use super::*;
+
+    fn entry(model: &str) -> HistoryEntry {
+        HistoryEntry { model: model.to_string(), drawing: Drawing::default() }
+    }
+
+    #[test]
+    fn fresh_history_cannot_undo_or_redo() {
+        let history = History::new();
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn undo_then_redo_round_trips() {
+        let mut history = History::new();
+        history.push(entry("a"));
+        history.push(entry("b"));
+        assert_eq!(history.undo().map(|e| e.model.as_str()), Some("a"));
+        assert!(history.can_redo());
+        assert_eq!(history.redo().map(|e| e.model.as_str()), Some("b"));
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn push_after_undo_truncates_the_redo_tail() {
+        let mut history = History::new();
+        history.push(entry("a"));
+        history.push(entry("b"));
+        history.undo();
+        history.push(entry("c"));
+        assert!(!history.can_redo());
+        assert_eq!(history.undo().map(|e| e.model.as_str()), Some("a"));
+    }
+
+    #[test]
+    fn undo_past_the_start_returns_none() {
+        let mut history = History::new();
+        history.push(entry("a"));
+        history.push(entry("b"));
+        assert!(history.undo().is_some());
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn push_beyond_max_entries_drops_the_oldest() {
+        let mut history = History::new();
+        for i in 0..History::MAX_ENTRIES + 10 {
+            history.push(entry(&i.to_string()));
+        }
+        assert_eq!(history.entries.len(), History::MAX_ENTRIES);
+        assert_eq!(history.entries.first().unwrap().model, "10");
+    }
+}
+
 // ============================================================================
 // MAIN APPLICATION
 // ============================================================================
@@ -279,24 +1030,160 @@ pub fn app(cx: Scope<AppProps>) -> Element {
     let drawing = use_state(&cx, || {
         draw(initial_model.clone()).unwrap_or_default()
     });
+    let status = use_state(&cx, || AppStatus::Ready);
 
-    // Processing coroutine - complexity hidden inside
+    // Pan/zoom framing for the drawing pane, plus the drag origin for an
+    // in-progress pan gesture (`None` when the pointer isn't down).
+    let view = use_state(&cx, viewport::Viewport::default);
+    let drag_origin = use_ref(&cx, || None::<(f32, f32, f32, f32)>);
+
+    // Debounce generation for writing the model back into the URL: a write
+    // scheduled after a draw only runs if no newer draw has completed by
+    // the time its delay elapses.
+    let url_sync_gen = use_ref(&cx, || 0u64);
+
+    // Undo/redo stack, plus its own debounce generation so rapid edits
+    // collapse into one history entry instead of one per keystroke.
+    let history = use_ref(&cx, History::new);
+    let history_gen = use_ref(&cx, || 0u64);
+
+    // `latest_req_id` lets the main thread discard any worker response that
+    // is no longer current (stale-result suppression): only the response
+    // whose `req_id` matches the most recently issued request is applied.
+    let latest_req_id = use_ref(&cx, || 0u64);
+
+    // The dedicated Web Worker that actually runs layout, created once and
+    // reused for every request.
+    #[cfg(target_arch = "wasm32")]
+    let worker_bridge = use_ref(&cx, worker_bridge::WorkerBridge::new);
+
+    // Processing coroutine: forwards each keystroke to the layout worker as
+    // a `worker::DrawRequest` and applies `worker::DrawResponse`s in order,
+    // dropping stale ones. A `gloo_timers` `TimeoutFuture` races each
+    // request so a pathological model flips `status` to `Timeout` instead
+    // of hanging the editor.
     let drawing_client = use_coroutine(&cx, |mut rx: UnboundedReceiver<String>| {
-        to_owned![drawing, model];
+        to_owned![drawing, model, status, latest_req_id, url_sync_gen, history, history_gen];
+        #[cfg(target_arch = "wasm32")]
+        to_owned![worker_bridge];
         async move {
             while let Some(current_model) = rx.next().await {
-                let nodes = if current_model.trim().is_empty() {
-                    Ok(Ok(Drawing::default()))
+                let req_id = {
+                    let mut latest = latest_req_id.write();
+                    *latest += 1;
+                    *latest
+                };
+                let request = worker::DrawRequest { req_id, model: current_model.clone() };
+
+                if ENABLE_STATUS_TRACKING {
+                    status.set(AppStatus::Processing);
+                }
+
+                // On wasm32, `response_fut` only resolves once the worker
+                // actually posts a reply back - there is no synchronous
+                // fallback - so racing it against `TimeoutFuture` below is a
+                // genuine race, not one that resolves on first poll.
+                #[cfg(target_arch = "wasm32")]
+                let response_fut = worker_bridge.read().request(request);
+                #[cfg(not(target_arch = "wasm32"))]
+                let response_fut = async { worker::handle_request(request) };
+
+                #[cfg(target_arch = "wasm32")]
+                let response = if ENABLE_TIMEOUT_DETECTION {
+                    use futures::future::{select, Either};
+                    use timeout_support::TimeoutFuture;
+
+                    match select(response_fut, TimeoutFuture::new(DRAW_TIMEOUT_MS)).await {
+                        Either::Left((Ok(response), _)) => Some(response),
+                        Either::Left((Err(_), _)) => None, // bridge dropped the sender
+                        Either::Right(_) => {
+                            // The worker is a single `onmessage` queue: if it's
+                            // stuck computing the timed-out request, it'll never
+                            // get to any request queued behind it either.
+                            // Terminate it and spin up a fresh one so future
+                            // keystrokes aren't wedged behind the abandoned draw.
+                            *worker_bridge.write() = worker_bridge::WorkerBridge::new();
+                            None
+                        }
+                    }
                 } else {
-                    catch_unwind(|| draw(current_model.clone()))
+                    response_fut.await.ok()
                 };
-                
-                match nodes {
-                    Ok(Ok(drawing_nodes)) => {
+                #[cfg(not(target_arch = "wasm32"))]
+                let response = Some(response_fut.await);
+
+                let response = match response {
+                    Some(response) => response,
+                    None => {
+                        if req_id == *latest_req_id.read() {
+                            status.set(AppStatus::Timeout);
+                        }
+                        continue;
+                    }
+                };
+
+                // Stale-result suppression: ignore responses to requests
+                // that are no longer the latest in flight.
+                if response.req_id != *latest_req_id.read() {
+                    continue;
+                }
+
+                match response.result {
+                    Ok(drawing_nodes) => {
+                        if ENABLE_HISTORY {
+                            let entry = HistoryEntry {
+                                model: current_model.clone(),
+                                drawing: drawing_nodes.clone(),
+                            };
+                            let gen = {
+                                let mut g = history_gen.write();
+                                *g += 1;
+                                *g
+                            };
+                            let history = history.clone();
+                            let history_gen = history_gen.clone();
+                            #[cfg(target_arch = "wasm32")]
+                            wasm_bindgen_futures::spawn_local(async move {
+                                timeout_support::TimeoutFuture::new(HISTORY_SETTLE_MS).await;
+                                if *history_gen.read() == gen {
+                                    history.write().push(entry);
+                                }
+                            });
+                            #[cfg(not(target_arch = "wasm32"))]
+                            {
+                                let _ = gen;
+                                history.write().push(entry);
+                            }
+                        }
+
                         drawing.set(drawing_nodes);
+                        if ENABLE_STATUS_TRACKING {
+                            status.set(AppStatus::Ready);
+                        }
+
+                        // Debounce the permalink write: only the last draw
+                        // in a burst actually touches the URL.
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            let gen = {
+                                let mut g = url_sync_gen.write();
+                                *g += 1;
+                                *g
+                            };
+                            let drawn_model = current_model.clone();
+                            let url_sync_gen = url_sync_gen.clone();
+                            wasm_bindgen_futures::spawn_local(async move {
+                                timeout_support::TimeoutFuture::new(500).await;
+                                if *url_sync_gen.read() == gen {
+                                    permalink::sync_url(&drawn_model);
+                                }
+                            });
+                        }
                     },
-                    Ok(Err(_)) | Err(_) => {
-                        // Errors are silently ignored in base version
+                    Err(msg) => {
+                        if ENABLE_STATUS_TRACKING {
+                            status.set(AppStatus::Error(msg));
+                        }
                     }
                 }
             }
@@ -306,8 +1193,21 @@ pub fn app(cx: Scope<AppProps>) -> Element {
     // UI rendering
     let nodes = render(cx, drawing.get().clone());
     let viewbox_width = drawing.viewbox_width;
-    let data_svg = as_data_svg(drawing.get().clone(), true);
+    let data_svg = export_svg_with_viewport(&as_data_svg(drawing.get().clone(), true), view.get());
     let syntax_guide = depict::graph_drawing::frontend::dioxus::syntax_guide(cx)?;
+    let status_text = match status.get() {
+        AppStatus::Ready => None,
+        AppStatus::Processing => Some("Drawing…".to_string()),
+        AppStatus::Timeout => Some("Layout did not finish in time — showing the last completed drawing.".to_string()),
+        AppStatus::Error(msg) => Some(format!("Error: {msg}")),
+    };
+
+    // Degrade gracefully to plain text if tokenization panics on some input:
+    // the textarea itself is rendered transparent, so an empty segment list
+    // here would show the user a blank box rather than their model.
+    let model_text = model.get().clone();
+    let overlay_segments = catch_unwind(|| highlight::segments(&model_text))
+        .unwrap_or_else(|_| vec![(None, model_text.clone())]);
 
     cx.render(rsx!{
         div {
@@ -316,11 +1216,71 @@ pub fn app(cx: Scope<AppProps>) -> Element {
                 div {
                     "Model"
                 }
-                
-                // Text Editor
+
+                // Undo/redo toolbar - buttons mirror the Ctrl+Z / Ctrl+Shift+Z
+                // key handling on the editor below.
+                div {
+                    style: "display: flex; flex-direction: row; gap: 0.5em;",
+                    button {
+                        disabled: "{!history.read().can_undo()}",
+                        onclick: move |_| {
+                            if let Some(entry) = history.write().undo() {
+                                let restored_model = entry.model.clone();
+                                model.set(restored_model.clone());
+                                drawing.set(entry.drawing.clone());
+                                // Invalidate any worker response still in flight for the
+                                // undone edit, and keep the permalink in sync immediately.
+                                *latest_req_id.write() += 1;
+                                *url_sync_gen.write() += 1;
+                                #[cfg(target_arch = "wasm32")]
+                                permalink::sync_url(&restored_model);
+                            }
+                        },
+                        "Undo"
+                    }
+                    button {
+                        disabled: "{!history.read().can_redo()}",
+                        onclick: move |_| {
+                            if let Some(entry) = history.write().redo() {
+                                let restored_model = entry.model.clone();
+                                model.set(restored_model.clone());
+                                drawing.set(entry.drawing.clone());
+                                *latest_req_id.write() += 1;
+                                *url_sync_gen.write() += 1;
+                                #[cfg(target_arch = "wasm32")]
+                                permalink::sync_url(&restored_model);
+                            }
+                        },
+                        "Redo"
+                    }
+                }
+
+                // Text Editor - a transparent textarea layered over a div of
+                // colored spans (the classic syntax-highlighting overlay
+                // technique); both share the same font/padding so the
+                // highlight stays pixel-aligned as the user scrolls or wraps.
                 div {
+                    style: "position: relative;",
+                    div {
+                        id: "model_highlight_overlay",
+                        class: "model_highlight_overlay",
+                        "aria-hidden": "true",
+                        style: "position: absolute; inset: 0; box-sizing: border-box; width: calc(100% - 2em); padding: 1px; margin: 0; border-width: 1px; border-color: transparent; font: inherit; white-space: pre-wrap; word-wrap: break-word; overflow: hidden; pointer-events: none; color: #000;",
+                        overlay_segments.iter().map(|(kind, text)| {
+                            let class = match kind {
+                                Some(highlight::TokenKind::Noun) => "tok-noun",
+                                Some(highlight::TokenKind::EdgeLabel) => "tok-edge-label",
+                                Some(highlight::TokenKind::RelationSeparator) => "tok-relation-separator",
+                                Some(highlight::TokenKind::ForwardReverseSeparator) => "tok-forward-reverse-separator",
+                                Some(highlight::TokenKind::Comment) => "tok-comment",
+                                None => "tok-plain",
+                            };
+                            rsx!{ span { class: "{class}", "{text}" } }
+                        })
+                    }
                     textarea {
-                        style: "box-sizing: border-box; width: calc(100% - 2em); border-width: 1px; border-color: #000;",
+                        id: "model_editor",
+                        style: "position: relative; background: transparent; color: transparent; caret-color: #000; box-sizing: border-box; width: calc(100% - 2em); border-width: 1px; border-color: #000;",
                         rows: "10",
                         autocomplete: "off",
                         "autocapitalize": "off",
@@ -331,10 +1291,41 @@ pub fn app(cx: Scope<AppProps>) -> Element {
                             model.set(e.value.clone());
                             drawing_client.send(e.value.clone());
                         },
+                        onscroll: move |_| sync_overlay_scroll(),
+                        onkeydown: move |evt| {
+                            // With Shift held the key arrives as "Z", so match either case.
+                            let is_z = matches!(evt.key(), keyboard_types::Key::Character(ref c) if c.eq_ignore_ascii_case("z"));
+                            if !evt.ctrl_key() || !is_z {
+                                return;
+                            }
+                            evt.prevent_default();
+                            let restored = if evt.shift_key() {
+                                history.write().redo().cloned()
+                            } else {
+                                history.write().undo().cloned()
+                            };
+                            if let Some(entry) = restored {
+                                let restored_model = entry.model;
+                                model.set(restored_model.clone());
+                                drawing.set(entry.drawing);
+                                *latest_req_id.write() += 1;
+                                *url_sync_gen.write() += 1;
+                                #[cfg(target_arch = "wasm32")]
+                                permalink::sync_url(&restored_model);
+                            }
+                        },
                         "{model}"
                     }
                 }
-                
+
+                // Status line - reflects AppStatus as reported by the layout worker
+                status_text.as_ref().map(|text| rsx!{
+                    div {
+                        style: "font-size: 0.875rem; line-height: 1.25rem; color: #666;",
+                        "{text}"
+                    }
+                })
+
                 // Footer
                 div {
                     style: "display: flex; flex-direction: row; justify-content: space-between;",
@@ -352,6 +1343,19 @@ pub fn app(cx: Scope<AppProps>) -> Element {
                                     "Export SVG"
                                 }
                             }
+                            div {
+                                a {
+                                    href: "#",
+                                    onclick: move |evt| {
+                                        evt.stop_propagation();
+                                        let url = permalink::share_url(model.get());
+                                        if let Some(clipboard) = web_sys::window().map(|w| w.navigator().clipboard()) {
+                                            let _ = clipboard.write_text(&url);
+                                        }
+                                    },
+                                    "Copy link"
+                                }
+                            }
                             div {
                                 details {
                                     summary {
@@ -395,11 +1399,65 @@ pub fn app(cx: Scope<AppProps>) -> Element {
                 }
             }
         }
-        // DRAWING
+        // DRAWING - `content` fills its parent and hosts the pan/zoom
+        // viewport; `view`'s transform is applied to the `nodes` container
+        // rather than to `content` itself, so wheel/drag coordinates stay in
+        // untransformed, container-local pixels.
         div {
+            id: "drawing_viewport",
             class: "content",
+            style: "position: relative; width: 100%; height: 100%; overflow: hidden; border-width: 1px; border-color: #000;",
+            onwheel: move |evt| {
+                let coords = evt.element_coordinates();
+                let factor = if evt.delta_y() < 0.0 { 1.1 } else { 1.0 / 1.1 };
+                view.set(view.get().zoom_at(coords.x as f32, coords.y as f32, factor));
+            },
+            onmousedown: move |evt| {
+                let c = evt.client_coordinates();
+                let v = *view.get();
+                *drag_origin.write() = Some((c.x as f32, c.y as f32, v.translate.0, v.translate.1));
+            },
+            onmousemove: move |evt| {
+                if let Some((origin_x, origin_y, start_tx, start_ty)) = *drag_origin.read() {
+                    let c = evt.client_coordinates();
+                    let mut v = *view.get();
+                    v.translate = (start_tx + (c.x as f32 - origin_x), start_ty + (c.y as f32 - origin_y));
+                    view.set(v);
+                }
+            },
+            onmouseup: move |_| {
+                *drag_origin.write() = None;
+            },
+            onmouseleave: move |_| {
+                *drag_origin.write() = None;
+            },
+            div {
+                style: "position: absolute; top: 0.5em; right: 0.5em; z-index: 1;",
+                button {
+                    onclick: move |_| {
+                        let container = web_sys::window()
+                            .and_then(|w| w.document())
+                            .and_then(|d| d.get_element_by_id("drawing_viewport"))
+                            .map(|el| el.get_bounding_client_rect());
+                        if let Some(rect) = container {
+                            view.set(viewport::Viewport::fit(
+                                viewbox_width as f32,
+                                drawing.get().viewbox_height as f32,
+                                rect.width() as f32,
+                                rect.height() as f32,
+                            ));
+                        }
+                    },
+                    "Fit to view"
+                }
+            }
             div {
-                style: "position: relative; width: {viewbox_width}px; margin-left: auto; margin-right: auto; border-width: 1px; border-color: #000;",
+                // No `margin: auto` centering here - `css_transform`'s
+                // translate already does that (`Viewport::fit` computes it
+                // relative to this div's untransformed top-left origin), and
+                // `zoom_at`'s "keep the point under the cursor fixed" math
+                // assumes that origin isn't also being shifted by layout.
+                style: "position: relative; width: {viewbox_width}px; border-width: 1px; border-color: #000; {view.get().css_transform()}",
                 nodes
             }
         }
@@ -417,6 +1475,10 @@ fn main() {
     style.set_inner_html(DEFAULT_CSS);
     head.append_child(&style).unwrap();
 
+    let highlight_style = document.create_element("style").unwrap();
+    highlight_style.set_inner_html(HIGHLIGHT_CSS);
+    head.append_child(&highlight_style).unwrap();
+
     dioxus_web::launch_with_props(
         app,
         AppProps {},